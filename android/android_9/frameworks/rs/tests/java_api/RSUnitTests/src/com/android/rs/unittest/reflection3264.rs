@@ -21,12 +21,23 @@ typedef struct user_t {
   uint x;
   uint y;
   rs_allocation alloc;
+  half h;
+  half2 h2;
+  half4 h4;
 } user;
 
 uchar4 expect_ans;
 uint expect_x;
 uint expect_y;
 
+half expect_h;
+half2 expect_h2;
+half4 expect_h4;
+
+rs_allocation sharedAlloc;
+uchar4 *sharedBasePtr;
+uint32_t sharedStride;
+
 uint32_t expect_dAlloc_GetDimX;
 int expect_dXOff;
 int expect_dMip;
@@ -47,12 +58,31 @@ void root(uchar4 *output, const user * usr, uint x, uint y) {
     _RS_ASSERT(usr->ans.w == expect_ans.w);
     _RS_ASSERT(usr->x == expect_x);
     _RS_ASSERT(usr->y == expect_y);
+
+    // fp16 members: check separately, they have different alignment rules
+    _RS_ASSERT(usr->h == expect_h);
+    _RS_ASSERT(usr->h2.x == expect_h2.x);
+    _RS_ASSERT(usr->h2.y == expect_h2.y);
+    _RS_ASSERT(usr->h4.x == expect_h4.x);
+    _RS_ASSERT(usr->h4.y == expect_h4.y);
+    _RS_ASSERT(usr->h4.z == expect_h4.z);
+    _RS_ASSERT(usr->h4.w == expect_h4.w);
   }
 
   uchar4 * e_in = (uchar4*)rsGetElementAt(usr->alloc, x, y);
   *output = *e_in;
 }
 
+// Checks rsGetElementAt() against a directly-mapped USAGE_SHARED pointer
+void sharedCheck(uint32_t x, uint32_t y) {
+  uchar4 *e_api = (uchar4 *)rsGetElementAt(sharedAlloc, x, y);
+  uchar4 *e_ptr = (uchar4 *)((uint8_t *)sharedBasePtr + y * sharedStride + x * sizeof(uchar4));
+  _RS_ASSERT(e_ptr->x == e_api->x);
+  _RS_ASSERT(e_ptr->y == e_api->y);
+  _RS_ASSERT(e_ptr->z == e_api->z);
+  _RS_ASSERT(e_ptr->w == e_api->w);
+}
+
 // See http://b/32780232 "Corrupted rs_allocation instances when passed as arguments to invocables"
 void args(rs_allocation dAlloc, int dXOff, int dMip, int count,
           rs_allocation sAlloc, int sXOff, int sMip) {